@@ -1,17 +1,35 @@
 use {
 	crate::{event::Event, install_dir, Error, GSIConfig, Result},
 	axum::{
-		extract::{Json, State},
+		extract::{
+			ws::{Message, WebSocket, WebSocketUpgrade},
+			Json, State,
+		},
 		http::StatusCode,
-		response::IntoResponse,
-		routing::post,
+		response::{IntoResponse, Response},
+		routing::{get, post},
 		Router,
 	},
-	axum_server::{Handle, Server},
-	std::{fmt::Debug, future::Future, net::SocketAddr, path::PathBuf, pin::Pin},
+	axum_server::Handle,
+	reqwest::{Client, Url},
+	std::{
+		fmt::Debug,
+		future::Future,
+		net::{SocketAddr, TcpListener},
+		path::PathBuf,
+		pin::Pin,
+		sync::{
+			atomic::{AtomicBool, Ordering},
+			Arc,
+		},
+		time::Duration,
+	},
 	tokio::{
-		sync::mpsc::{self, UnboundedSender},
-		task::AbortHandle,
+		sync::{
+			broadcast,
+			mpsc::{self, UnboundedSender},
+		},
+		task::JoinHandle,
 	},
 	tracing::{error, info},
 };
@@ -29,6 +47,73 @@ pub struct GSIServer {
 	listeners: Vec<Box<dyn FnMut(Event) + Send + Sync>>,
 	/// The registered async callback funtions to execute when an event fires.
 	async_listeners: Vec<AsyncCallback>,
+	/// Whether to expose the `GET /ws` WebSocket feed for external subscribers.
+	websocket: bool,
+	/// Upstream URLs each [`Event`] should additionally be POSTed to.
+	forward_targets: Vec<Url>,
+	/// How many times delivery of a forwarded event is attempted before giving up on a target.
+	forward_max_retries: usize,
+	/// Initial delay between forwarding retries; doubled after each failed attempt.
+	forward_base_backoff: Duration,
+}
+
+/// Shared state handed to every Axum request handler.
+#[derive(Clone)]
+struct AppState {
+	/// Channel into the dispatch loop that runs the registered listeners.
+	sender: UnboundedSender<Event>,
+	/// Broadcast side of the optional WebSocket feed, present only when it is enabled. Carries
+	/// already-serialized JSON frames so each event is encoded once, not once per subscriber.
+	ws_sender: Option<broadcast::Sender<String>>,
+	/// The `auth.token` an incoming payload must carry, if any.
+	auth: AuthToken,
+}
+
+/// The GSI `auth.token` a payload must carry to be accepted.
+///
+/// Modelled as its own type rather than a bare `Option<String>` so that "no token required" is an
+/// explicit, self-documenting state; the check stays opt-in and therefore backward compatible.
+#[derive(Clone, Debug, Default)]
+pub enum AuthToken {
+	/// No token is required; every payload is accepted.
+	#[default]
+	None,
+	/// A token is required and the payload's `auth.token` must match it exactly.
+	Required(String),
+}
+
+impl AuthToken {
+	/// Whether the `provided` token (if any) satisfies this requirement.
+	fn accepts(&self, provided: Option<&str>) -> bool {
+		match self {
+			Self::None => true,
+			Self::Required(expected) => provided == Some(expected.as_str()),
+		}
+	}
+}
+
+/// How many events the WebSocket broadcast channel buffers before lagging subscribers start
+/// missing messages.
+const WEBSOCKET_CHANNEL_CAPACITY: usize = 1024;
+
+/// How many events the webhook-forwarding queue buffers before new events are dropped rather than
+/// blocking local dispatch.
+const FORWARD_QUEUE_CAPACITY: usize = 1024;
+
+/// Default number of times a single event delivery is attempted before giving up on a target.
+const FORWARD_MAX_RETRIES: usize = 3;
+
+/// Default initial delay between forwarding retries; doubled after each failed attempt.
+const FORWARD_BASE_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Retry/backoff settings for webhook forwarding, threaded from the [`GSIServer`] into the
+/// per-target delivery tasks.
+#[derive(Clone, Copy, Debug)]
+struct ForwardRetry {
+	/// How many times a delivery is attempted before giving up on a target.
+	max_retries: usize,
+	/// Initial delay between retries; doubled after each failed attempt.
+	base_backoff: Duration,
 }
 
 /// Alias for convenience.
@@ -37,6 +122,10 @@ pub type AsyncCallback = Box<dyn FnMut(Event) -> BoxedFuture + Send + Sync>;
 /// Alias for convenience.
 pub type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send + Sync>>;
 
+/// How long the HTTP server is given to finish in-flight requests during a graceful
+/// [`ServerHandle::shutdown`].
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[allow(unused)]
 #[cfg(test)]
 mod thread_safety {
@@ -56,9 +145,35 @@ impl GSIServer {
 			installed: false,
 			listeners: Vec::new(),
 			async_listeners: Vec::new(),
+			websocket: false,
+			forward_targets: Vec::new(),
+			forward_max_retries: FORWARD_MAX_RETRIES,
+			forward_base_backoff: FORWARD_BASE_BACKOFF,
 		}
 	}
 
+	/// Register an upstream `url` that every [`Event`] should be forwarded to over HTTP, in
+	/// addition to the local listeners. Call repeatedly to fan out to several targets.
+	pub fn add_forward_target(&mut self, url: Url) -> &mut Self {
+		self.forward_targets.push(url);
+		self
+	}
+
+	/// Tune the retry behaviour for forwarded events: `max_retries` attempts per target, starting
+	/// with `base_backoff` between them and doubling after each failure.
+	pub fn set_forward_retry(&mut self, max_retries: usize, base_backoff: Duration) -> &mut Self {
+		self.forward_max_retries = max_retries;
+		self.forward_base_backoff = base_backoff;
+		self
+	}
+
+	/// Enable the `GET /ws` WebSocket feed so external processes (HUD overlays, browser sources,
+	/// companion apps) can subscribe to the live event stream over a standard protocol.
+	pub fn enable_websocket_feed(&mut self) -> &mut Self {
+		self.websocket = true;
+		self
+	}
+
 	/// Install the server's configuration into CS:GO's cfg folder.
 	pub fn install(&mut self) -> Result<&mut Self> {
 		if !self.installed {
@@ -71,10 +186,15 @@ impl GSIServer {
 	}
 
 	/// Set the installation directory for the server.
+	///
+	/// The expected `auth.token` (if one is configured) is threaded into the generated cfg so
+	/// CS:GO echoes it back on every update; otherwise [`handle_update`] would reject every real
+	/// payload with `401`.
 	pub fn install_into<P: Into<PathBuf> + Debug>(&mut self, cfg_folder: P) -> Result<&mut Self> {
 		if !self.installed {
+			let token = self.config.auth_token();
 			self.config
-				.install_into(cfg_folder, self.port)?;
+				.install_into(cfg_folder, self.port, token.as_deref())?;
 			self.installed = true;
 			return Ok(self);
 		}
@@ -102,23 +222,72 @@ impl GSIServer {
 
 	/// Start the server. This will give you a [`ServerHandle`] that can be used to stop the server
 	/// later.
+	///
+	/// When the server was constructed with port `0` to request an ephemeral port, installation is
+	/// deferred until after binding so the cfg is written with the resolved port rather than the
+	/// non-routable `0`.
 	#[tracing::instrument(skip(self))]
 	pub fn run(mut self) -> Result<ServerHandle> {
-		if !self.installed {
-			self.install()?;
-		}
-
 		let (sender, mut receiver) = mpsc::unbounded_channel::<Event>();
 
+		// Only stand up the broadcast channel when the feed is actually enabled.
+		let ws_sender = self
+			.websocket
+			.then(|| broadcast::channel::<String>(WEBSOCKET_CHANNEL_CAPACITY).0);
+
 		let addr = SocketAddr::from(([127, 0, 0, 1], self.port));
 
-		info!("Starting server on {addr}.");
+		// Bind eagerly so a failure (most commonly the port already being in use) is surfaced to
+		// the caller synchronously instead of vanishing inside the spawned task. Passing `0` as the
+		// port asks the OS for an ephemeral one; `local_addr` then reports what we actually got.
+		let listener = TcpListener::bind(addr).map_err(|err| {
+			if err.kind() == std::io::ErrorKind::AddrInUse {
+				Error::AddrInUse { port: self.port }
+			} else {
+				Error::Io(err)
+			}
+		})?;
+		let local_addr = listener.local_addr().map_err(Error::Io)?;
+
+		// Install only now that the real port is known, so an ephemeral (`0`) port still produces a
+		// cfg CS:GO can actually POST to.
+		self.port = local_addr.port();
+		if !self.installed {
+			self.install()?;
+		}
+
+		info!("Starting server on {local_addr}.");
 		let http_handle = Handle::new();
-		tokio::spawn(run_server(addr, sender, http_handle.clone()));
+		let auth = match self.config.auth_token() {
+			Some(token) => AuthToken::Required(token),
+			None => AuthToken::None,
+		};
+		let state = AppState { sender: sender.clone(), ws_sender: ws_sender.clone(), auth };
+		tokio::spawn(run_server(listener, state, http_handle.clone()));
+
+		// A bounded queue feeding a dedicated task keeps slow or down upstreams from ever blocking
+		// local dispatch or backing up the mpsc channel.
+		let forward_sender = (!self.forward_targets.is_empty()).then(|| {
+			let (tx, rx) = mpsc::channel::<Event>(FORWARD_QUEUE_CAPACITY);
+			let retry = ForwardRetry {
+				max_retries: self.forward_max_retries,
+				base_backoff: self.forward_base_backoff,
+			};
+			tokio::spawn(run_forwarder(self.forward_targets.clone(), rx, retry));
+			tx
+		});
 
 		info!("Listening for events...");
-		let server_handle = tokio::spawn(async move {
+		let paused = Arc::new(AtomicBool::new(false));
+		let dispatch_paused = Arc::clone(&paused);
+		let dispatch_handle = tokio::spawn(async move {
 			while let Some(event) = receiver.recv().await {
+				// While paused we keep draining the channel (so it never backs up) but skip the
+				// listeners; the socket and cfg stay in place.
+				if dispatch_paused.load(Ordering::Relaxed) {
+					continue;
+				}
+
 				for cb in &mut self.listeners {
 					cb(event.clone());
 				}
@@ -126,41 +295,111 @@ impl GSIServer {
 				for async_cb in &mut self.async_listeners {
 					async_cb(event.clone()).await;
 				}
+
+				// Fan the event out to every connected WebSocket client. Serialize once here and
+				// broadcast the encoded frame so N subscribers don't each re-encode it. A send
+				// error just means nobody is currently subscribed, which is fine.
+				if let Some(ws_sender) = &ws_sender {
+					match serde_json::to_string(&event) {
+						Ok(payload) => {
+							let _ = ws_sender.send(payload);
+						}
+						Err(why) => error!("Failed to serialize event for WebSocket feed: {why:?}"),
+					}
+				}
+
+				// Hand the event to the forwarding task. Dropping it when the queue is full is
+				// preferable to stalling the local listeners on a slow upstream.
+				if let Some(forward_sender) = &forward_sender {
+					if forward_sender.try_send(event.clone()).is_err() {
+						error!("Forward queue full, dropping event for upstream targets.");
+					}
+				}
 			}
-		})
-		.abort_handle();
+		});
 
-		Ok(ServerHandle { server_handle, http_handle })
+		Ok(ServerHandle { dispatch_handle, http_handle, sender, local_addr, paused })
 	}
 }
 
-/// A handle to abort a running server after spawning it.
+/// A handle to stop a running server after spawning it.
 #[derive(Debug)]
 pub struct ServerHandle {
-	server_handle: AbortHandle,
+	dispatch_handle: JoinHandle<()>,
 	http_handle: Handle,
+	sender: UnboundedSender<Event>,
+	local_addr: SocketAddr,
+	paused: Arc<AtomicBool>,
 }
 
 impl ServerHandle {
+	/// The address the server is actually listening on.
+	///
+	/// Useful when the server was started with port `0` to obtain an OS-assigned ephemeral port,
+	/// so the real port can be written into the generated `.cfg` or logged.
+	pub fn local_addr(&self) -> SocketAddr {
+		self.local_addr
+	}
+
+	/// Temporarily stop invoking the registered listeners while keeping the HTTP listener bound
+	/// and the cfg installed. Incoming updates are still drained and discarded until
+	/// [`ServerHandle::resume`] is called.
+	pub fn pause(&self) {
+		self.paused.store(true, Ordering::Relaxed);
+	}
+
+	/// Resume invoking the registered listeners after a [`ServerHandle::pause`].
+	pub fn resume(&self) {
+		self.paused.store(false, Ordering::Relaxed);
+	}
+
 	/// Will abort the execution of both the GSI server and the HTTP server spawned by it.
+	///
+	/// This is the immediate variant: any events still queued in the channel and any
+	/// currently-awaiting async listeners are dropped. Use [`ServerHandle::shutdown`] to drain
+	/// in-flight work first.
 	pub fn abort(self) {
-		self.server_handle.abort();
+		self.dispatch_handle.abort();
 		self.http_handle.shutdown();
 	}
+
+	/// Gracefully stop the server, draining any in-flight events before returning.
+	///
+	/// First the HTTP server stops accepting new POSTs (existing requests get
+	/// [`GRACEFUL_SHUTDOWN_TIMEOUT`] to finish), then the sender side of the channel is dropped so
+	/// `receiver.recv()` returns `None` once drained, and finally the dispatch task is awaited so
+	/// every queued [`Event`] reaches all sync and async listeners.
+	pub async fn shutdown(self) {
+		self.http_handle
+			.graceful_shutdown(Some(GRACEFUL_SHUTDOWN_TIMEOUT));
+
+		// Clear any active pause so the final drain actually delivers the backlog to listeners
+		// instead of discarding it — pause only ever suppresses live dispatch, never shutdown.
+		self.paused.store(false, Ordering::Relaxed);
+
+		// Dropping our sender (and the one held by the now-stopping HTTP server) closes the
+		// channel, which lets the dispatch loop finish once the backlog is delivered.
+		drop(self.sender);
+
+		if let Err(why) = self.dispatch_handle.await {
+			error!("Dispatch task did not shut down cleanly: {why:?}");
+		}
+	}
 }
 
 /// Launches the Axum server for listening to CS:GO updates.
-#[tracing::instrument]
-async fn run_server(
-	addr: SocketAddr,
-	sender: UnboundedSender<Event>,
-	handle: Handle,
-) -> Result<()> {
-	let router = Router::new()
-		.route("/", post(handle_update))
-		.with_state(sender);
+#[tracing::instrument(skip(state))]
+async fn run_server(listener: TcpListener, state: AppState, handle: Handle) -> Result<()> {
+	let mut router = Router::new().route("/", post(handle_update));
+
+	// Only mount the WebSocket route when the feed was enabled.
+	if state.ws_sender.is_some() {
+		router = router.route("/ws", get(handle_ws_upgrade));
+	}
+
+	let router = router.with_state(state);
 
-	Server::bind(addr)
+	axum_server::from_tcp(listener)
 		.handle(handle)
 		.serve(router.into_make_service())
 		.await
@@ -169,12 +408,105 @@ async fn run_server(
 	Ok(())
 }
 
-#[tracing::instrument]
-pub async fn handle_update(
-	State(sender): State<UnboundedSender<Event>>,
+/// Drains the forwarding queue and POSTs each event to every configured upstream target.
+///
+/// Delivery failures are retried with exponential backoff and counted per target so a persistently
+/// unreachable endpoint is logged rather than aborting the relay.
+#[tracing::instrument(skip(targets, events))]
+async fn run_forwarder(targets: Vec<Url>, mut events: mpsc::Receiver<Event>, retry: ForwardRetry) {
+	let client = Client::new();
+
+	// One bounded queue and task per target, so retries against a slow or down endpoint only ever
+	// delay (and drop for) that endpoint — the healthy targets keep receiving events.
+	let senders = targets
+		.into_iter()
+		.map(|target| {
+			let (tx, rx) = mpsc::channel::<String>(FORWARD_QUEUE_CAPACITY);
+			tokio::spawn(forward_to_target(client.clone(), target, rx, retry));
+			tx
+		})
+		.collect::<Vec<_>>();
+
+	while let Some(event) = events.recv().await {
+		let payload = match serde_json::to_string(&event) {
+			Ok(payload) => payload,
+			Err(why) => {
+				error!("Failed to serialize event for forwarding: {why:?}");
+				continue;
+			}
+		};
+
+		for sender in &senders {
+			if sender.try_send(payload.clone()).is_err() {
+				error!("Forward queue for an upstream target is full, dropping event.");
+			}
+		}
+	}
+}
+
+/// Delivers payloads to a single upstream target, counting failures so a persistently unreachable
+/// endpoint is logged rather than silently retried forever.
+#[tracing::instrument(skip(client, payloads))]
+async fn forward_to_target(
+	client: Client,
+	target: Url,
+	mut payloads: mpsc::Receiver<String>,
+	retry: ForwardRetry,
+) {
+	let mut failures = 0_u64;
+
+	while let Some(payload) = payloads.recv().await {
+		if let Err(why) = forward_with_retry(&client, &target, &payload, retry).await {
+			failures += 1;
+			error!("Failed to forward event to {target} ({failures} total): {why}");
+		}
+	}
+}
+
+/// POSTs `payload` to a single `target`, making at most `retry.max_retries` attempts in total
+/// with exponential backoff between them.
+async fn forward_with_retry(
+	client: &Client,
+	target: &Url,
+	payload: &str,
+	retry: ForwardRetry,
+) -> reqwest::Result<()> {
+	let mut backoff = retry.base_backoff;
+
+	for attempt in 1..=retry.max_retries {
+		let result = client
+			.post(target.clone())
+			.header("content-type", "application/json")
+			.body(payload.to_owned())
+			.send()
+			.await
+			.and_then(|response| response.error_for_status());
+
+		match result {
+			Ok(_) => return Ok(()),
+			Err(why) if attempt == retry.max_retries => return Err(why),
+			Err(_) => {
+				tokio::time::sleep(backoff).await;
+				backoff *= 2;
+			}
+		}
+	}
+
+	Ok(())
+}
+
+#[tracing::instrument(skip(state))]
+pub(crate) async fn handle_update(
+	State(state): State<AppState>,
 	Json(body): Json<Event>,
 ) -> impl IntoResponse {
-	match sender.send(body.clone()) {
+	// Reject forged payloads before they reach any listener.
+	if !state.auth.accepts(body.auth_token()) {
+		error!("Rejecting update with missing or invalid auth token.");
+		return (StatusCode::UNAUTHORIZED, Json(body));
+	}
+
+	match state.sender.send(body.clone()) {
 		Ok(()) => (StatusCode::OK, Json(body)),
 		Err(why) => {
 			error!("Failed to send event to main thread: {why:?}");
@@ -182,3 +514,33 @@ pub async fn handle_update(
 		}
 	}
 }
+
+/// Upgrades a `GET /ws` request to a WebSocket and streams every [`Event`] to the client.
+#[tracing::instrument(skip(state, upgrade))]
+async fn handle_ws_upgrade(upgrade: WebSocketUpgrade, State(state): State<AppState>) -> Response {
+	let Some(ws_sender) = state.ws_sender else {
+		return StatusCode::NOT_FOUND.into_response();
+	};
+
+	upgrade.on_upgrade(move |socket| ws_feed(socket, ws_sender.subscribe()))
+}
+
+/// Forwards pre-serialized JSON frames from the broadcast channel to a single connected client.
+///
+/// A client that can't keep up simply misses the messages it lagged past instead of stalling the
+/// dispatch loop; an outright closed/erroring socket ends the feed.
+async fn ws_feed(mut socket: WebSocket, mut events: broadcast::Receiver<String>) {
+	loop {
+		match events.recv().await {
+			Ok(payload) => {
+				if socket.send(Message::Text(payload)).await.is_err() {
+					break;
+				}
+			}
+			Err(broadcast::error::RecvError::Lagged(skipped)) => {
+				error!("WebSocket client lagged behind, skipping {skipped} events.");
+			}
+			Err(broadcast::error::RecvError::Closed) => break,
+		}
+	}
+}